@@ -111,6 +111,14 @@ pub trait ClientContext: Send + Sync {
 
     /// Receives global errors from the librdkafka client.
     ///
+    /// Errors are reported as [`KafkaError::Global`]. Most of them, like
+    /// [`RDKafkaErrorCode::AllBrokersDown`], are informational and librdkafka
+    /// will keep trying to recover. Fatal errors are reported as
+    /// `KafkaError::Global(RDKafkaErrorCode::Fatal)`, with the underlying
+    /// error described in `reason`, and the client is no longer usable
+    /// afterwards; the underlying error code can be retrieved with
+    /// [`Client::fatal_error`].
+    ///
     /// The default implementation logs the error at the `error` log level.
     fn error(&self, error: KafkaError, reason: &str) {
         error!("librdkafka: {}: {}", error, reason);
@@ -328,7 +336,13 @@ impl<C: ClientContext> Client<C> {
 
     fn handle_error_event(&self, event: *mut RDKafkaEvent) {
         let rdkafka_err = unsafe { rdsys::rd_kafka_event_error(event) };
-        let error = KafkaError::Global(rdkafka_err.into());
+        // librdkafka replaces the `Fatal` code with the underlying error
+        // before handing out the event, so restore it from the fatal flag.
+        let error = if unsafe { rdsys::rd_kafka_event_error_is_fatal(event) } != 0 {
+            KafkaError::Global(RDKafkaErrorCode::Fatal)
+        } else {
+            KafkaError::Global(rdkafka_err.into())
+        };
         let reason =
             unsafe { CStr::from_ptr(rdsys::rd_kafka_event_error_string(event)).to_string_lossy() };
         self.context().error(error, reason.trim());
@@ -457,6 +471,58 @@ impl<C: ClientContext> Client<C> {
         Ok((low, high))
     }
 
+    /// Returns the low and high watermarks for the specified topic and
+    /// partition, as last cached by the client.
+    ///
+    /// Unlike [`Client::fetch_watermarks`], this method doesn't query the
+    /// broker. The high watermark is updated with every fetch response, while
+    /// the low watermark is only updated periodically if
+    /// `statistics.interval.ms` is set. Watermarks that have not been cached
+    /// yet are reported as the raw value of
+    /// [`Offset::Invalid`](crate::topic_partition_list::Offset::Invalid).
+    pub fn cached_watermarks(&self, topic: &str, partition: i32) -> KafkaResult<(i64, i64)> {
+        let mut low = -1;
+        let mut high = -1;
+        let topic_c = CString::new(topic.to_string())?;
+        let ret = unsafe {
+            rdsys::rd_kafka_get_watermark_offsets(
+                self.native_ptr(),
+                topic_c.as_ptr(),
+                partition,
+                &mut low as *mut i64,
+                &mut high as *mut i64,
+            )
+        };
+        if ret.is_error() {
+            return Err(KafkaError::MetadataFetch(ret.into()));
+        }
+        Ok((low, high))
+    }
+
+    /// Returns the name of the client instance, as assigned by librdkafka.
+    ///
+    /// The name is made of the configured `client.id` followed by a suffix
+    /// that is unique within the process, e.g. `rdkafka#producer-1`.
+    pub fn name(&self) -> String {
+        let name = unsafe { CStr::from_ptr(rdsys::rd_kafka_name(self.native_ptr())) };
+        name.to_string_lossy().into_owned()
+    }
+
+    /// Returns the group member id assigned to this client by the group
+    /// coordinator, or `None` if the client is not a member of a consumer
+    /// group.
+    pub fn member_id(&self) -> Option<String> {
+        let member_id = unsafe { rdsys::rd_kafka_memberid(self.native_ptr()) };
+        if member_id.is_null() {
+            return None;
+        }
+        let result = unsafe { CStr::from_ptr(member_id) }
+            .to_string_lossy()
+            .into_owned();
+        unsafe { rdsys::rd_kafka_mem_free(self.native_ptr(), member_id as *mut c_void) };
+        Some(result).filter(|member_id| !member_id.is_empty())
+    }
+
     /// Returns the cluster identifier option or None if the cluster identifier is null
     pub fn fetch_cluster_id<T: Into<Timeout>>(&self, timeout: T) -> Option<String> {
         let cluster_id =
@@ -620,4 +686,81 @@ mod tests {
         .unwrap();
         assert!(!client.native_ptr().is_null());
     }
+
+    #[test]
+    fn test_client_name_and_member_id() {
+        let config = ClientConfig::new().set("client.id", "named").clone();
+        let native_config = config.create_native_config().unwrap();
+        let client = Client::new(
+            &config,
+            native_config,
+            RDKafkaType::RD_KAFKA_CONSUMER,
+            DefaultClientContext,
+        )
+        .unwrap();
+        assert!(client.name().starts_with("named#consumer-"));
+        assert_eq!(client.member_id(), None);
+    }
+
+    #[test]
+    fn test_fatal_error_event() {
+        use std::sync::Mutex;
+        use std::time::Duration;
+
+        use crate::producer::{BaseProducer, Producer, ProducerContext};
+
+        #[derive(Default)]
+        struct ErrorContext(Mutex<Vec<KafkaError>>);
+
+        impl ClientContext for ErrorContext {
+            fn error(&self, error: KafkaError, _reason: &str) {
+                self.0.lock().unwrap().push(error);
+            }
+        }
+
+        impl ProducerContext for ErrorContext {
+            type DeliveryOpaque = ();
+
+            fn delivery(&self, _: &crate::message::DeliveryResult<'_>, _: Self::DeliveryOpaque) {}
+        }
+
+        let producer: BaseProducer<ErrorContext> = ClientConfig::new()
+            .create_with_context(ErrorContext::default())
+            .unwrap();
+        let reason = CString::new("injected").unwrap();
+        unsafe {
+            rdsys::rd_kafka_test_fatal_error(
+                producer.client().native_ptr(),
+                RDKafkaRespErr::RD_KAFKA_RESP_ERR_OUT_OF_ORDER_SEQUENCE_NUMBER,
+                reason.as_ptr(),
+            );
+        }
+        producer.poll(Duration::from_millis(100));
+
+        let errors = producer.context().0.lock().unwrap();
+        assert_eq!(*errors, vec![KafkaError::Global(RDKafkaErrorCode::Fatal)]);
+        assert!(errors[0].is_fatal());
+        assert!(!errors[0].is_retriable());
+        drop(errors);
+        assert_eq!(
+            producer.client().fatal_error().map(|(code, _)| code),
+            Some(RDKafkaErrorCode::OutOfOrderSequenceNumber)
+        );
+    }
+
+    #[test]
+    fn test_clients_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Client>();
+        assert_send_sync::<crate::admin::AdminClient<DefaultClientContext>>();
+        assert_send_sync::<crate::consumer::BaseConsumer>();
+        assert_send_sync::<crate::consumer::StreamConsumer>();
+        assert_send_sync::<crate::producer::BaseProducer<crate::producer::DefaultProducerContext>>(
+        );
+        assert_send_sync::<
+            crate::producer::ThreadedProducer<crate::producer::DefaultProducerContext>,
+        >();
+        assert_send_sync::<crate::producer::FutureProducer>();
+    }
 }
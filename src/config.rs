@@ -20,13 +20,26 @@
 //! - `statistics.interval.ms`: how often the statistic callback
 //!    specified in the [`ClientContext`] will be called. Default: 0 (disabled).
 //!
+//! ### Topic-level parameters
+//!
+//! Topic-level parameters, such as `acks`, `compression.codec` or
+//! `partitioner`, can be set on the [`ClientConfig`] like any other parameter.
+//! librdkafka applies them to the default topic configuration, which is used
+//! for every topic the client produces to or consumes from.
+//!
 //! [librdkafka-config]: https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
 
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::fs;
+use std::io;
 use std::iter::FromIterator;
 use std::os::raw::c_char;
+use std::path::Path;
 use std::ptr;
+use std::slice;
+use std::str::FromStr;
 
 use rdkafka_sys as rdsys;
 use rdkafka_sys::types::*;
@@ -76,6 +89,35 @@ impl RDKafkaLogLevel {
     }
 }
 
+/// A compression codec for produced messages.
+///
+/// See [`ClientConfig::set_compression`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compression {
+    /// No compression.
+    None,
+    /// Gzip compression. Requires librdkafka to be built with zlib.
+    Gzip,
+    /// Snappy compression.
+    Snappy,
+    /// LZ4 compression.
+    Lz4,
+    /// Zstandard compression. Requires librdkafka to be built with zstd.
+    Zstd,
+}
+
+impl Compression {
+    fn as_str(self) -> &'static str {
+        match self {
+            Compression::None => "none",
+            Compression::Gzip => "gzip",
+            Compression::Snappy => "snappy",
+            Compression::Lz4 => "lz4",
+            Compression::Zstd => "zstd",
+        }
+    }
+}
+
 //
 // ********** CLIENT CONFIG **********
 //
@@ -103,6 +145,31 @@ impl NativeClientConfig {
         self.ptr.ptr()
     }
 
+    /// Sets a parameter in the native configuration.
+    fn set(&self, key: &str, value: &str) -> KafkaResult<()> {
+        let mut err_buf = ErrBuf::new();
+        let key_c = CString::new(key.to_string())?;
+        let value_c = CString::new(value.to_string())?;
+        let ret = unsafe {
+            rdsys::rd_kafka_conf_set(
+                self.ptr(),
+                key_c.as_ptr(),
+                value_c.as_ptr(),
+                err_buf.as_mut_ptr(),
+                err_buf.capacity(),
+            )
+        };
+        if ret.is_error() {
+            return Err(KafkaError::ClientConfig(
+                ret,
+                err_buf.to_string(),
+                key.to_string(),
+                value.to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Gets the value of a parameter in the configuration.
     ///
     /// This method reflects librdkafka's view of the current value of the
@@ -149,13 +216,56 @@ impl NativeClientConfig {
             return Err(make_err(res));
         }
 
-        // Convert the C string to a Rust string.
-        Ok(String::from_utf8_lossy(&buf).to_string())
+        // Convert the C string to a Rust string, dropping the trailing NUL.
+        Ok(String::from_utf8_lossy(&buf)
+            .trim_end_matches(char::from(0))
+            .to_string())
+    }
+
+    /// Returns every parameter in the configuration as `(key, value)` pairs.
+    ///
+    /// Like [`NativeClientConfig::get`], this reflects librdkafka's view of the
+    /// configuration, including default values. The values of sensitive
+    /// parameters, such as passwords and private keys, are replaced with
+    /// `[redacted]`, so that the result can safely be logged.
+    pub fn dump(&self) -> Vec<(String, String)> {
+        let mut cnt = 0_usize;
+        let arr = unsafe { rdsys::rd_kafka_conf_dump(self.ptr(), &mut cnt) };
+        let entries = unsafe { slice::from_raw_parts(arr, cnt) };
+        let pairs = entries
+            .chunks_exact(2)
+            .map(|pair| {
+                let key = unsafe { CStr::from_ptr(pair[0]) }
+                    .to_string_lossy()
+                    .into_owned();
+                let value = if is_sensitive_key(&key) {
+                    REDACTED.to_string()
+                } else {
+                    unsafe { CStr::from_ptr(pair[1]) }
+                        .to_string_lossy()
+                        .into_owned()
+                };
+                (key, value)
+            })
+            .collect();
+        unsafe { rdsys::rd_kafka_conf_dump_free(arr, cnt) };
+        pairs
     }
 }
 
+/// The value shown in place of sensitive configuration parameters.
+const REDACTED: &str = "[redacted]";
+
+/// Returns whether the value of the configuration parameter `key` must not
+/// be logged.
+fn is_sensitive_key(key: &str) -> bool {
+    key.contains("password")
+        || key.contains("secret")
+        || matches!(key, "ssl.key.pem" | "ssl_key" | "sasl.oauthbearer.config")
+}
+
 /// Client configuration.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct ClientConfig {
     conf_map: HashMap<String, String>,
     /// The librdkafka logging level. Refer to [`RDKafkaLogLevel`] for the list
@@ -163,6 +273,27 @@ pub struct ClientConfig {
     pub log_level: RDKafkaLogLevel,
 }
 
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let conf_map: HashMap<_, _> = self
+            .conf_map
+            .iter()
+            .map(|(key, value)| {
+                let value = if is_sensitive_key(key) {
+                    REDACTED
+                } else {
+                    value.as_str()
+                };
+                (key.as_str(), value)
+            })
+            .collect();
+        f.debug_struct("ClientConfig")
+            .field("conf_map", &conf_map)
+            .field("log_level", &self.log_level)
+            .finish()
+    }
+}
+
 impl Default for ClientConfig {
     fn default() -> Self {
         Self::new()
@@ -178,6 +309,24 @@ impl ClientConfig {
         }
     }
 
+    /// Reads a configuration from a file in the format accepted by the
+    /// [`FromStr`] implementation.
+    ///
+    /// Returns a [`KafkaError::ConfigFile`] error if the file cannot be read
+    /// or is not valid UTF-8.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> KafkaResult<ClientConfig> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .map_err(|e| KafkaError::ConfigFile(e.kind(), format!("{}: {}", path.display(), e)))?;
+        let contents = String::from_utf8(bytes).map_err(|e| {
+            KafkaError::ConfigFile(
+                io::ErrorKind::InvalidData,
+                format!("{}: file is not valid UTF-8: {}", path.display(), e),
+            )
+        })?;
+        contents.parse()
+    }
+
     /// Gets a reference to the underlying config map
     pub fn config_map(&self) -> &HashMap<String, String> {
         &self.conf_map
@@ -209,6 +358,35 @@ impl ClientConfig {
         self
     }
 
+    /// Sets a parameter in the configuration, checking it with librdkafka
+    /// first.
+    ///
+    /// This works like [`ClientConfig::set`], but returns an error if
+    /// librdkafka does not know `key` or does not accept `value` for it, for
+    /// example because of a typo in the parameter name. The configuration is
+    /// left unchanged in that case. Errors that depend on the combination of
+    /// parameters are still only reported when the client is created.
+    pub fn set_checked<K, V>(&mut self, key: K, value: V) -> KafkaResult<&mut ClientConfig>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let (key, value) = (key.into(), value.into());
+        let conf = unsafe { NativeClientConfig::from_ptr(rdsys::rd_kafka_conf_new()) };
+        conf.set(&key, &value)?;
+        Ok(self.set(key, value))
+    }
+
+    /// Sets the compression codec used by producers, i.e. the
+    /// `compression.codec` parameter.
+    ///
+    /// Returns an error if the codec is not supported by this build of
+    /// librdkafka, for example [`Compression::Zstd`] without the `zstd`
+    /// feature, instead of leaving the messages uncompressed.
+    pub fn set_compression(&mut self, compression: Compression) -> KafkaResult<&mut ClientConfig> {
+        self.set_checked("compression.codec", compression.as_str())
+    }
+
     /// Removes a parameter from the configuration.
     pub fn remove<'a>(&'a mut self, key: &str) -> &'a mut ClientConfig {
         self.conf_map.remove(key);
@@ -225,27 +403,8 @@ impl ClientConfig {
     /// Builds a native librdkafka configuration.
     pub fn create_native_config(&self) -> KafkaResult<NativeClientConfig> {
         let conf = unsafe { NativeClientConfig::from_ptr(rdsys::rd_kafka_conf_new()) };
-        let mut err_buf = ErrBuf::new();
         for (key, value) in &self.conf_map {
-            let key_c = CString::new(key.to_string())?;
-            let value_c = CString::new(value.to_string())?;
-            let ret = unsafe {
-                rdsys::rd_kafka_conf_set(
-                    conf.ptr(),
-                    key_c.as_ptr(),
-                    value_c.as_ptr(),
-                    err_buf.as_mut_ptr(),
-                    err_buf.capacity(),
-                )
-            };
-            if ret.is_error() {
-                return Err(KafkaError::ClientConfig(
-                    ret,
-                    err_buf.to_string(),
-                    key.to_string(),
-                    value.to_string(),
-                ));
-            }
+            conf.set(key, value)?;
         }
         Ok(conf)
     }
@@ -276,6 +435,74 @@ impl FromIterator<(String, String)> for ClientConfig {
     }
 }
 
+/// Parses a configuration in a `key=value` subset of the Java properties
+/// format.
+///
+/// Every non-empty line must contain a `key=value` pair with a non-empty key.
+/// Whitespace around keys and values is trimmed, and lines starting with `#`
+/// or `!` are ignored as comments. Comments are only recognized at the start
+/// of a line, so a `#` inside a value is kept. If a key occurs more than once,
+/// the last value wins.
+///
+/// Other features of the properties format are not supported: `:` and
+/// whitespace are not recognized as separators, and backslashes are kept
+/// as is rather than treated as escapes. Lines ending in a backslash, which
+/// the properties format treats as continued on the next line, are rejected.
+///
+/// ```
+/// use rdkafka::config::ClientConfig;
+///
+/// let properties = "# Consumer settings\nbootstrap.servers = localhost:9092\ngroup.id = my-group";
+/// let config: ClientConfig = properties.parse().unwrap();
+/// assert_eq!(config.get("group.id"), Some("my-group"));
+/// ```
+impl FromStr for ClientConfig {
+    type Err = KafkaError;
+
+    fn from_str(s: &str) -> KafkaResult<ClientConfig> {
+        let mut config = ClientConfig::new();
+        for line in s.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => {
+                    return Err(KafkaError::ClientConfig(
+                        RDKafkaConfRes::RD_KAFKA_CONF_INVALID,
+                        "Missing '=' between key and value".into(),
+                        line.into(),
+                        "".into(),
+                    ))
+                }
+            };
+            if key.is_empty() {
+                return Err(KafkaError::ClientConfig(
+                    RDKafkaConfRes::RD_KAFKA_CONF_INVALID,
+                    "Missing key before '='".into(),
+                    line.into(),
+                    "".into(),
+                ));
+            }
+            if value.ends_with('\\') {
+                // Leave the value out of the error, as it may be a secret.
+                return Err(KafkaError::ClientConfig(
+                    RDKafkaConfRes::RD_KAFKA_CONF_INVALID,
+                    "Line continuations are not supported".into(),
+                    key.into(),
+                    "".into(),
+                ));
+            }
+            // Reject values that could never be passed to librdkafka.
+            CString::new(key)?;
+            CString::new(value)?;
+            config.set(key, value);
+        }
+        Ok(config)
+    }
+}
+
 impl Extend<(String, String)> for ClientConfig {
     fn extend<I>(&mut self, iter: I)
     where
@@ -313,7 +540,12 @@ pub trait FromClientConfigAndContext<C: ClientContext>: Sized {
 
 #[cfg(test)]
 mod tests {
-    use super::ClientConfig;
+    use std::fs;
+    use std::io;
+
+    use super::{ClientConfig, Compression};
+    use crate::error::KafkaError;
+    use crate::producer::BaseProducer;
 
     #[test]
     fn test_client_config_set_map() {
@@ -326,4 +558,177 @@ mod tests {
         assert_eq!(config.get("b").unwrap(), "2");
         assert_eq!(config.get("c").unwrap(), "3");
     }
+
+    #[test]
+    fn test_client_config_clone() {
+        let mut original = ClientConfig::new();
+        original.set("client.id", "original");
+
+        let mut clone = original.clone();
+        clone.set("client.id", "clone").set("linger.ms", "10");
+        let _producer: BaseProducer = clone.create().unwrap();
+
+        assert_eq!(original.get("client.id"), Some("original"));
+        assert_eq!(original.get("linger.ms"), None);
+        assert_eq!(clone.get("client.id"), Some("clone"));
+    }
+
+    #[test]
+    fn test_client_config_from_str() {
+        let config: ClientConfig = "
+            # A comment
+            ! Another comment
+
+            bootstrap.servers=localhost:9092
+              group.id   =   first
+            sasl.password = p#ss=word
+            group.id = second
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(config.config_map().len(), 3);
+        assert_eq!(config.get("bootstrap.servers"), Some("localhost:9092"));
+        assert_eq!(config.get("sasl.password"), Some("p#ss=word"));
+        assert_eq!(config.get("group.id"), Some("second"));
+    }
+
+    #[test]
+    fn test_client_config_from_str_invalid() {
+        match "bootstrap.servers".parse::<ClientConfig>() {
+            Err(KafkaError::ClientConfig(_, _, key, _)) => assert_eq!(key, "bootstrap.servers"),
+            res => panic!("unexpected result: {:?}", res),
+        }
+        match "client.id=a\n = value".parse::<ClientConfig>() {
+            Err(KafkaError::ClientConfig(_, _, key, _)) => assert_eq!(key, "= value"),
+            res => panic!("unexpected result: {:?}", res),
+        }
+        match "group.id=a\0b".parse::<ClientConfig>() {
+            Err(KafkaError::Nul(_)) => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+        let continued = "sasl.jaas.config=PlainLoginModule required \\\n    username=\"alice\"";
+        match continued.parse::<ClientConfig>() {
+            Err(KafkaError::ClientConfig(_, _, key, value)) => {
+                assert_eq!(key, "sasl.jaas.config");
+                assert_eq!(value, "");
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_client_config_from_file() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("rdkafka-config-{}.properties", std::process::id()));
+
+        fs::write(&path, "# Settings\nbootstrap.servers = localhost:9092\n").unwrap();
+        let config = ClientConfig::from_file(&path).unwrap();
+        assert_eq!(config.get("bootstrap.servers"), Some("localhost:9092"));
+
+        fs::write(&path, b"client.id=\xff\n").unwrap();
+        match ClientConfig::from_file(&path) {
+            Err(KafkaError::ConfigFile(kind, _)) => assert_eq!(kind, io::ErrorKind::InvalidData),
+            res => panic!("unexpected result: {:?}", res),
+        }
+
+        fs::remove_file(&path).unwrap();
+        match ClientConfig::from_file(&path) {
+            Err(KafkaError::ConfigFile(kind, _)) => assert_eq!(kind, io::ErrorKind::NotFound),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_native_client_config_get_and_dump() {
+        let native = ClientConfig::new()
+            .set("client.id", "audit")
+            .set("sasl.password", "secret")
+            .create_native_config()
+            .unwrap();
+
+        assert_eq!(native.get("client.id").unwrap(), "audit");
+        assert_eq!(native.get("sasl.password").unwrap(), "secret");
+        assert!(native.get("no.such.key").is_err());
+
+        let dump = native.dump();
+        assert!(dump.contains(&("client.id".to_string(), "audit".to_string())));
+        assert!(dump.contains(&("sasl.password".to_string(), "[redacted]".to_string())));
+        assert!(dump.iter().all(|(_, value)| value != "secret"));
+    }
+
+    #[test]
+    fn test_client_config_set_checked() {
+        let mut config = ClientConfig::new();
+        config
+            .set_checked("bootstrap.servers", "localhost:9092")
+            .unwrap()
+            .set_checked("message.max.bytes", "2000")
+            .unwrap();
+        assert_eq!(config.get("bootstrap.servers"), Some("localhost:9092"));
+        assert_eq!(config.get("message.max.bytes"), Some("2000"));
+
+        match config.set_checked("bootstrap.server", "localhost:9092") {
+            Err(KafkaError::ClientConfig(_, desc, key, _)) => {
+                assert_eq!(key, "bootstrap.server");
+                assert!(desc.contains("bootstrap.server"), "{}", desc);
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+        assert!(matches!(
+            config.set_checked("message.max.bytes", "not a number"),
+            Err(KafkaError::ClientConfig(_, _, _, _))
+        ));
+        assert_eq!(config.get("bootstrap.server"), None);
+        assert_eq!(config.get("message.max.bytes"), Some("2000"));
+    }
+
+    #[test]
+    fn test_client_config_debug_redacts_secrets() {
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", "localhost:9092")
+            .set("sasl.password", "hunter2")
+            .set("sasl.oauthbearer.client.secret", "hunter3");
+        let debug = format!("{:?}", config);
+        assert!(debug.contains("\"bootstrap.servers\": \"localhost:9092\""));
+        assert!(debug.contains("\"sasl.password\": \"[redacted]\""));
+        assert!(!debug.contains("hunter"));
+    }
+
+    #[test]
+    fn test_client_config_topic_level_parameters() {
+        let native = ClientConfig::new()
+            .set("partitioner", "murmur2")
+            .set("message.timeout.ms", "1234")
+            .create_native_config()
+            .unwrap();
+
+        assert_eq!(native.get("partitioner").unwrap(), "murmur2");
+        assert_eq!(native.get("message.timeout.ms").unwrap(), "1234");
+    }
+
+    #[test]
+    fn test_client_config_set_compression() {
+        let mut config = ClientConfig::new();
+        for compression in [Compression::None, Compression::Snappy, Compression::Lz4] {
+            config.set_compression(compression).unwrap();
+        }
+        assert_eq!(config.get("compression.codec"), Some("lz4"));
+        #[cfg(feature = "zstd")]
+        {
+            config.set_compression(Compression::Zstd).unwrap();
+            assert_eq!(config.get("compression.codec"), Some("zstd"));
+        }
+        // Without zstd support in librdkafka, the codec is rejected and the
+        // previous setting is kept.
+        #[cfg(not(any(feature = "zstd", feature = "zstd-pkg-config")))]
+        {
+            assert!(matches!(
+                config.set_compression(Compression::Zstd),
+                Err(KafkaError::ClientConfig(..))
+            ));
+            assert_eq!(config.get("compression.codec"), Some("lz4"));
+        }
+    }
 }
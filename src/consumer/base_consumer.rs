@@ -118,6 +118,40 @@ where
         self.poll_queue(self.get_queue(), timeout)
     }
 
+    /// Polls the consumer for a batch of up to `max_messages` messages.
+    ///
+    /// This keeps calling [`BaseConsumer::poll`] until either `max_messages`
+    /// messages (or errors) have been received or the timeout expires, and
+    /// returns whatever was received so far. Messages that are already
+    /// available are returned even if the timeout is zero. The returned
+    /// vector may be empty.
+    ///
+    /// As with [`BaseConsumer::poll`], any events waiting to be handled are
+    /// served while polling.
+    ///
+    /// # Lifetime
+    ///
+    /// The returned messages live in the memory of the consumer and cannot
+    /// outlive it.
+    pub fn poll_batch<T: Into<Timeout>>(
+        &self,
+        max_messages: usize,
+        timeout: T,
+    ) -> Vec<KafkaResult<BorrowedMessage<'_>>> {
+        let start = Instant::now();
+        let timeout = timeout.into();
+        let mut batch = Vec::new();
+        while batch.len() < max_messages {
+            let remaining = timeout.saturating_sub(start.elapsed());
+            match self.poll(remaining) {
+                Some(result) => batch.push(result),
+                None if remaining.is_zero() => break,
+                None => {}
+            }
+        }
+        batch
+    }
+
     pub(crate) fn poll_queue<T: Into<Timeout>>(
         &self,
         queue: &NativeQueue,
@@ -357,6 +391,29 @@ where
         unsafe { rdsys::rd_kafka_consumer_closed(self.client.native_ptr()) == 1 }
     }
 
+    /// Closes the consumer, blocking until the close completes.
+    ///
+    /// If the consumer is part of a consumer group, this commits the final
+    /// offsets (when `enable.auto.commit` is set), revokes the current
+    /// assignment and leaves the group, so that the remaining members can
+    /// rebalance right away instead of waiting for the session to time out.
+    /// Any rebalance callbacks triggered by the close are served on the
+    /// calling thread.
+    ///
+    /// Calling this method is optional: dropping the consumer performs the
+    /// same close. It does nothing if the consumer has no `group.id` or is
+    /// already closed.
+    pub fn close(&self) -> KafkaResult<()> {
+        if self.group_id.is_none() || self.closed() {
+            return Ok(());
+        }
+        self.close_queue()?;
+        while !self.closed() {
+            self.poll(Duration::from_millis(100));
+        }
+        Ok(())
+    }
+
     pub(crate) fn native_client(&self) -> &NativeClient {
         self.client.native_client()
     }
@@ -723,14 +780,8 @@ where
 {
     fn drop(&mut self) {
         trace!("Destroying consumer: {:?}", self.client.native_ptr());
-        if self.group_id.is_some() {
-            if let Err(err) = self.close_queue() {
-                error!("Failed to close consumer queue on drop: {}", err);
-            } else {
-                while !self.closed() {
-                    self.poll(Duration::from_millis(100));
-                }
-            }
+        if let Err(err) = self.close() {
+            error!("Failed to close consumer queue on drop: {}", err);
         }
         trace!("Consumer destroyed: {:?}", self.client.native_ptr());
     }
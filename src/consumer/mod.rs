@@ -236,6 +236,13 @@ where
 
     /// Manually assigns topics and partitions to the consumer. If used,
     /// automatic consumer rebalance won't be activated.
+    ///
+    /// The offset of each element in the list determines where consumption of
+    /// that partition starts. Use [`Offset::Stored`] to resume from the offset
+    /// committed for the consumer's `group.id`; [`Offset::Invalid`], the
+    /// offset of a newly added partition, behaves the same way. If no offset
+    /// has been committed for a partition, the consumer starts according to
+    /// `auto.offset.reset`.
     fn assign(&self, assignment: &TopicPartitionList) -> KafkaResult<()>;
 
     /// Clears all topic and partitions currently assigned to the consumer
@@ -250,6 +257,9 @@ where
     /// Seeks to `offset` for the specified `topic` and `partition`. After a
     /// successful call to `seek`, the next poll of the consumer will return the
     /// message with `offset`.
+    ///
+    /// The partition must be assigned to the consumer, otherwise a
+    /// [`KafkaError::Seek`] error is returned.
     fn seek<T: Into<Timeout>>(
         &self,
         topic: &str,
@@ -298,11 +308,14 @@ where
     /// This method is exactly equivalent to invoking [`Consumer::commit`]
     /// with a [`TopicPartitionList`] which copies the topic and partition
     /// from the message and adds 1 to the offset of the message.
+    ///
+    /// With [`CommitMode::Sync`], this blocks until the broker has
+    /// acknowledged the commit, and returns any error it reported.
     fn commit_message(&self, message: &BorrowedMessage<'_>, mode: CommitMode) -> KafkaResult<()>;
 
     /// Stores offset to be used on the next (auto)commit. When
     /// using this `enable.auto.offset.store` should be set to `false` in the
-    /// config.
+    /// config, otherwise a [`KafkaError::StoreOffset`] error is returned.
     fn store_offset(&self, topic: &str, partition: i32, offset: i64) -> KafkaResult<()>;
 
     /// Like [`Consumer::store_offset`], but the offset to store is derived from
@@ -310,7 +323,8 @@ where
     fn store_offset_from_message(&self, message: &BorrowedMessage<'_>) -> KafkaResult<()>;
 
     /// Store offsets to be used on the next (auto)commit. When using this
-    /// `enable.auto.offset.store` should be set to `false` in the config.
+    /// `enable.auto.offset.store` should be set to `false` in the config,
+    /// otherwise a [`KafkaError::StoreOffset`] error is returned.
     fn store_offsets(&self, tpl: &TopicPartitionList) -> KafkaResult<()>;
 
     /// Returns the current topic subscription.
@@ -360,6 +374,12 @@ where
         Self: Sized;
 
     /// Looks up the offsets for the specified partitions by timestamp.
+    ///
+    /// The offset of every element of `timestamps` must be set to
+    /// `Offset::Offset(timestamp)`, with the timestamp in milliseconds since
+    /// the Unix epoch. In the returned list, each offset is replaced by the
+    /// earliest offset whose timestamp is greater than or equal to the given
+    /// timestamp, or by [`Offset::End`] if there is no such message.
     fn offsets_for_times<T>(
         &self,
         timestamps: TopicPartitionList,
@@ -390,6 +410,22 @@ where
         T: Into<Timeout>,
         Self: Sized;
 
+    /// Returns the low and high watermarks for a specific topic and partition,
+    /// as cached by the consumer, without querying the broker.
+    ///
+    /// See [`Client::cached_watermarks`] for details.
+    fn cached_watermarks(&self, topic: &str, partition: i32) -> KafkaResult<(i64, i64)> {
+        self.client().cached_watermarks(topic, partition)
+    }
+
+    /// Returns the member id assigned to this consumer by the group
+    /// coordinator, or `None` if the consumer has not joined a group.
+    ///
+    /// See [`Client::member_id`] for details.
+    fn member_id(&self) -> Option<String> {
+        self.client().member_id()
+    }
+
     /// Returns the group membership information for the given group. If no group is
     /// specified, all groups will be returned.
     fn fetch_group_list<T>(&self, group: Option<&str>, timeout: T) -> KafkaResult<GroupList>
@@ -398,9 +434,16 @@ where
         Self: Sized;
 
     /// Pauses consumption for the provided list of partitions.
+    ///
+    /// Errors for individual partitions, such as partitions that are not
+    /// assigned to the consumer, are reported in the `error` field of the
+    /// corresponding element of `partitions`.
     fn pause(&self, partitions: &TopicPartitionList) -> KafkaResult<()>;
 
     /// Resumes consumption for the provided list of partitions.
+    ///
+    /// Errors for individual partitions are reported as for
+    /// [`Consumer::pause`].
     fn resume(&self, partitions: &TopicPartitionList) -> KafkaResult<()>;
 
     /// Reports the rebalance protocol in use.
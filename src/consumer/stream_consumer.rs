@@ -303,6 +303,15 @@ where
             .expect("kafka streams never terminate")
     }
 
+    /// Closes the consumer, blocking until the close completes.
+    ///
+    /// See [`BaseConsumer::close`] for details. Note that this method blocks
+    /// the calling thread; in an async context, consider running it with
+    /// `spawn_blocking` or similar.
+    pub fn close(&self) -> KafkaResult<()> {
+        self.base.close()
+    }
+
     /// Splits messages for the specified partition into their own stream.
     ///
     /// If the `topic` or `partition` is invalid, returns `None`.
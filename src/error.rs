@@ -3,6 +3,7 @@
 use std::error::Error;
 use std::ffi::{self, CStr};
 use std::fmt;
+use std::io;
 use std::ptr;
 use std::sync::Arc;
 
@@ -145,6 +146,8 @@ pub enum KafkaError {
     ClientConfig(RDKafkaConfRes, String, String, String),
     /// Client creation failed.
     ClientCreation(String),
+    /// Reading a configuration file failed.
+    ConfigFile(io::ErrorKind, String),
     /// Consumer commit failed.
     ConsumerCommit(RDKafkaErrorCode),
     /// Consumer queue close failed.
@@ -205,6 +208,9 @@ impl fmt::Debug for KafkaError {
             KafkaError::ClientCreation(ref err) => {
                 write!(f, "KafkaError (Client creation error: {})", err)
             }
+            KafkaError::ConfigFile(_, ref err) => {
+                write!(f, "KafkaError (Config file error: {})", err)
+            }
             KafkaError::ConsumerCommit(err) => {
                 write!(f, "KafkaError (Consumer commit error: {})", err)
             }
@@ -264,6 +270,7 @@ impl fmt::Display for KafkaError {
                 write!(f, "Client config error: {} {} {}", desc, key, value)
             }
             KafkaError::ClientCreation(ref err) => write!(f, "Client creation error: {}", err),
+            KafkaError::ConfigFile(_, ref err) => write!(f, "Config file error: {}", err),
             KafkaError::ConsumerCommit(err) => write!(f, "Consumer commit error: {}", err),
             KafkaError::ConsumerQueueClose(err) => write!(f, "Consumer queue close error: {}", err),
             KafkaError::Flush(err) => write!(f, "Flush error: {}", err),
@@ -296,11 +303,12 @@ impl fmt::Display for KafkaError {
 impl Error for KafkaError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            KafkaError::AdminOp(_) => None,
+            KafkaError::AdminOp(err) => Some(err),
             KafkaError::AdminOpCreation(_) => None,
             KafkaError::Canceled => None,
             KafkaError::ClientConfig(..) => None,
             KafkaError::ClientCreation(_) => None,
+            KafkaError::ConfigFile(..) => None,
             KafkaError::ConsumerCommit(err) => Some(err),
             KafkaError::ConsumerQueueClose(err) => Some(err),
             KafkaError::Flush(err) => Some(err),
@@ -311,7 +319,7 @@ impl Error for KafkaError {
             KafkaError::MessageProduction(err) => Some(err),
             KafkaError::MetadataFetch(err) => Some(err),
             KafkaError::NoMessageReceived => None,
-            KafkaError::Nul(_) => None,
+            KafkaError::Nul(err) => Some(err),
             KafkaError::OffsetFetch(err) => Some(err),
             KafkaError::PartitionEOF(_) => None,
             KafkaError::PauseResume(_) => None,
@@ -342,6 +350,7 @@ impl KafkaError {
             KafkaError::Canceled => None,
             KafkaError::ClientConfig(..) => None,
             KafkaError::ClientCreation(_) => None,
+            KafkaError::ConfigFile(..) => None,
             KafkaError::ConsumerCommit(err) => Some(*err),
             KafkaError::ConsumerQueueClose(err) => Some(*err),
             KafkaError::Flush(err) => Some(*err),
@@ -365,4 +374,111 @@ impl KafkaError {
             KafkaError::MockCluster(err) => Some(*err),
         }
     }
+
+    /// Returns whether retrying the failed operation may succeed.
+    ///
+    /// Transaction errors are classified by librdkafka. Other errors are
+    /// considered retriable if their [`RDKafkaErrorCode`] denotes a timeout, a
+    /// full queue or a transient broker-side condition, such as a leader
+    /// election in progress. Fatal errors are never retriable.
+    pub fn is_retriable(&self) -> bool {
+        if self.is_fatal() {
+            return false;
+        }
+        match self {
+            KafkaError::Transaction(err) => err.is_retriable(),
+            _ => matches!(
+                self.rdkafka_error_code(),
+                Some(
+                    RDKafkaErrorCode::OperationTimedOut
+                        | RDKafkaErrorCode::TimedOutQueue
+                        | RDKafkaErrorCode::QueueFull
+                        | RDKafkaErrorCode::BrokerTransportFailure
+                        | RDKafkaErrorCode::RequestTimedOut
+                        | RDKafkaErrorCode::NetworkException
+                        | RDKafkaErrorCode::LeaderNotAvailable
+                        | RDKafkaErrorCode::NotLeaderForPartition
+                        | RDKafkaErrorCode::NotEnoughReplicas
+                        | RDKafkaErrorCode::NotEnoughReplicasAfterAppend
+                        | RDKafkaErrorCode::CoordinatorLoadInProgress
+                        | RDKafkaErrorCode::CoordinatorNotAvailable
+                        | RDKafkaErrorCode::NotCoordinator
+                        | RDKafkaErrorCode::KafkaStorageError
+                        | RDKafkaErrorCode::ThrottlingQuotaExceeded
+                )
+            ),
+        }
+    }
+
+    /// Returns whether the error is fatal, in which case the client can no
+    /// longer be used and must be recreated.
+    ///
+    /// See [`Client::fatal_error`](crate::client::Client::fatal_error) for the
+    /// underlying error of a [`RDKafkaErrorCode::Fatal`] code.
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            KafkaError::Transaction(err) => err.is_fatal(),
+            KafkaError::MessageConsumptionFatal(_) => true,
+            _ => self.rdkafka_error_code() == Some(RDKafkaErrorCode::Fatal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_classification() {
+        let queue_full = KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull);
+        assert!(queue_full.is_retriable());
+        assert!(!queue_full.is_fatal());
+
+        let fatal = KafkaError::Global(RDKafkaErrorCode::Fatal);
+        assert!(!fatal.is_retriable());
+        assert!(fatal.is_fatal());
+
+        let fatal_consumption = KafkaError::MessageConsumptionFatal(RDKafkaErrorCode::Fail);
+        assert!(fatal_consumption.is_fatal());
+
+        let fatal_timeout =
+            KafkaError::MessageConsumptionFatal(RDKafkaErrorCode::OperationTimedOut);
+        assert!(fatal_timeout.is_fatal());
+        assert!(!fatal_timeout.is_retriable());
+
+        let invalid = KafkaError::MessageProduction(RDKafkaErrorCode::InvalidMessage);
+        assert!(!invalid.is_retriable());
+        assert!(!invalid.is_fatal());
+
+        assert!(!KafkaError::Canceled.is_retriable());
+        assert!(!KafkaError::Canceled.is_fatal());
+    }
+
+    #[test]
+    fn test_error_source() {
+        let global = KafkaError::Global(RDKafkaErrorCode::AllBrokersDown);
+        let source = global.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<RDKafkaErrorCode>(),
+            Some(&RDKafkaErrorCode::AllBrokersDown)
+        );
+        assert_eq!(
+            source.to_string(),
+            RDKafkaErrorCode::AllBrokersDown.to_string()
+        );
+
+        let admin_op = KafkaError::AdminOp(RDKafkaErrorCode::InvalidConfig);
+        assert_eq!(
+            admin_op
+                .source()
+                .unwrap()
+                .downcast_ref::<RDKafkaErrorCode>(),
+            Some(&RDKafkaErrorCode::InvalidConfig)
+        );
+
+        let nul: KafkaError = ffi::CString::new("a\0b").unwrap_err().into();
+        assert!(nul.source().unwrap().is::<ffi::NulError>());
+
+        assert!(KafkaError::Canceled.source().is_none());
+    }
 }
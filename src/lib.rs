@@ -78,6 +78,10 @@
 //! For more information about consumers and producers, refer to their
 //! module-level documentation.
 //!
+//! All the client types are `Send` and `Sync`, as librdkafka handles are
+//! thread safe. They can be shared between threads or tasks by wrapping them in
+//! an [`Arc`](std::sync::Arc); no `Mutex` is needed.
+//!
 //! *Warning*: the library is under active development and the APIs are likely
 //! to change.
 //!
@@ -2,15 +2,17 @@
 
 use std::ffi::{CStr, CString};
 use std::fmt;
+use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 use std::ptr;
 use std::str;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use rdkafka_sys as rdsys;
 use rdkafka_sys::types::*;
+use serde::Serialize;
 
 use crate::admin::NativeEvent;
 use crate::error::{IsError, KafkaError, KafkaResult};
@@ -320,14 +322,62 @@ unsafe impl KafkaDrop for RDKafkaMessage {
     const DROP: unsafe extern "C" fn(*mut Self) = no_op;
 }
 
+/// The number of key or payload bytes shown by the `Debug` implementations of
+/// the message types.
+const DEBUG_PREVIEW_LEN: usize = 64;
+
+/// Formats a key or payload for debugging: as a quoted string if it is valid
+/// UTF-8, as a list of hex bytes otherwise. Only the first
+/// [`DEBUG_PREVIEW_LEN`] bytes are shown, cut back to a character boundary
+/// for strings.
+struct BytesPreview<'a>(&'a [u8]);
+
+impl<'a> fmt::Debug for BytesPreview<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mut head, mut rest) = if self.0.len() > DEBUG_PREVIEW_LEN {
+            self.0.split_at(DEBUG_PREVIEW_LEN)
+        } else {
+            (self.0, &[][..])
+        };
+        let utf8 = match str::from_utf8(head) {
+            // The cut fell inside a multibyte character, which continues in
+            // the rest of the slice.
+            Err(e) if e.error_len().is_none() && !rest.is_empty() => {
+                (head, rest) = self.0.split_at(e.valid_up_to());
+                str::from_utf8(head)
+            }
+            utf8 => utf8,
+        };
+        match utf8 {
+            Ok(s) => write!(f, "{:?}", s)?,
+            Err(_) => {
+                write!(f, "[")?;
+                for (i, byte) in head.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:#04x}", byte)?;
+                }
+                write!(f, "]")?;
+            }
+        }
+        if !rest.is_empty() {
+            write!(f, " ... ({} more bytes)", rest.len())?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a> fmt::Debug for BorrowedMessage<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Message {{ ptr: {:?}, event_ptr: {:?} }}",
-            self.ptr(),
-            self._event.ptr()
-        )
+        f.debug_struct("BorrowedMessage")
+            .field("topic", &self.topic())
+            .field("partition", &self.partition())
+            .field("offset", &self.offset())
+            .field("key", &self.key().map(BytesPreview))
+            .field("payload", &self.payload().map(BytesPreview))
+            .field("timestamp", &self.timestamp())
+            .finish()
     }
 }
 
@@ -404,6 +454,27 @@ impl<'a> BorrowedMessage<'a> {
         self.ptr.len
     }
 
+    /// Returns the time elapsed between producing the message and receiving
+    /// its delivery report, for messages sent by a producer.
+    ///
+    /// Returns `None` for consumed messages, or if the latency is not
+    /// available.
+    pub fn latency(&self) -> Option<Duration> {
+        match unsafe { rdsys::rd_kafka_message_latency(self.ptr()) } {
+            latency if latency < 0 => None,
+            latency => Some(Duration::from_micros(latency as u64)),
+        }
+    }
+
+    /// Returns the id of the broker the message was produced to or consumed
+    /// from, or `None` if it is not known.
+    pub fn broker_id(&self) -> Option<i32> {
+        match unsafe { rdsys::rd_kafka_message_broker_id(self.ptr()) } {
+            -1 => None,
+            broker_id => Some(broker_id),
+        }
+    }
+
     /// Clones the content of the `BorrowedMessage` and returns an
     /// [`OwnedMessage`] that can outlive the consumer.
     ///
@@ -585,6 +656,20 @@ impl Headers for OwnedHeaders {
     }
 }
 
+impl<'a, V> FromIterator<Header<'a, &'a V>> for OwnedHeaders
+where
+    V: ToBytes + ?Sized + 'a,
+{
+    fn from_iter<I>(iter: I) -> OwnedHeaders
+    where
+        I: IntoIterator<Item = Header<'a, &'a V>>,
+    {
+        let iter = iter.into_iter();
+        let headers = OwnedHeaders::new_with_capacity(iter.size_hint().0);
+        iter.fold(headers, OwnedHeaders::insert)
+    }
+}
+
 impl Clone for OwnedHeaders {
     fn clone(&self) -> Self {
         OwnedHeaders {
@@ -598,7 +683,7 @@ impl Clone for OwnedHeaders {
 /// An `OwnedMessage` can be created from a [`BorrowedMessage`] using the
 /// [`BorrowedMessage::detach`] method. `OwnedMessage`s don't hold any reference
 /// to the consumer and don't use any memory inside the consumer buffer.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct OwnedMessage {
     payload: Option<Vec<u8>>,
     key: Option<Vec<u8>>,
@@ -693,6 +778,19 @@ impl OwnedMessage {
     }
 }
 
+impl fmt::Debug for OwnedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedMessage")
+            .field("topic", &self.topic)
+            .field("partition", &self.partition)
+            .field("offset", &self.offset)
+            .field("key", &self.key.as_deref().map(BytesPreview))
+            .field("payload", &self.payload.as_deref().map(BytesPreview))
+            .field("timestamp", &self.timestamp)
+            .finish()
+    }
+}
+
 impl Message for OwnedMessage {
     type Headers = OwnedHeaders;
 
@@ -822,6 +920,37 @@ impl ToBytes for () {
     }
 }
 
+/// A value serialized as JSON, to be used as the key or payload of a message.
+///
+/// The value is serialized when the `Json` is created, so that serialization
+/// errors are reported before the message is sent.
+///
+/// ```
+/// use rdkafka::message::Json;
+/// use rdkafka::producer::BaseRecord;
+///
+/// let payload = Json::new(&serde_json::json!({ "id": 1 })).unwrap();
+/// let record = BaseRecord::<str, _>::to("topic").payload(&payload);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Json(Vec<u8>);
+
+impl Json {
+    /// Serializes `value` as JSON.
+    pub fn new<T>(value: &T) -> serde_json::Result<Json>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_json::to_vec(value).map(Json)
+    }
+}
+
+impl ToBytes for Json {
+    fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 // Implement to_bytes for arrays - https://github.com/rust-lang/rfcs/issues/1038
 macro_rules! array_impls {
     ($($N:expr)+) => {
@@ -867,6 +996,50 @@ mod test {
         assert_eq!(t, Timestamp::CreateTime(100));
     }
 
+    #[test]
+    fn test_bytes_preview_multibyte_cut() {
+        let mut payload = "a".repeat(63);
+        payload.push_str("é and more");
+        assert_eq!(
+            format!("{:?}", BytesPreview(payload.as_bytes())),
+            format!("{:?} ... (11 more bytes)", "a".repeat(63))
+        );
+    }
+
+    #[test]
+    fn test_owned_message_debug() {
+        let message = OwnedMessage::new(
+            Some(vec![0xff; 100]),
+            Some(b"ab".to_vec()),
+            "events".to_owned(),
+            Timestamp::CreateTime(1700000000000),
+            3,
+            419,
+            None,
+        );
+        let expected = format!(
+            "OwnedMessage {{ topic: \"events\", partition: 3, offset: 419, key: Some(\"ab\"), \
+             payload: Some([{}] ... (36 more bytes)), timestamp: CreateTime(1700000000000) }}",
+            vec!["0xff"; 64].join(", ")
+        );
+        assert_eq!(format!("{:?}", message), expected);
+
+        let message = OwnedMessage::new(
+            None,
+            Some(vec![0x00, 0x9f]),
+            "events".to_owned(),
+            Timestamp::NotAvailable,
+            0,
+            0,
+            None,
+        );
+        assert_eq!(
+            format!("{:?}", message),
+            "OwnedMessage { topic: \"events\", partition: 0, offset: 0, key: Some([0x00, 0x9f]), \
+             payload: None, timestamp: NotAvailable }"
+        );
+    }
+
     #[test]
     fn test_headers() {
         let owned = OwnedHeaders::new()
@@ -893,4 +1066,84 @@ mod test {
             })
         );
     }
+
+    #[test]
+    fn test_headers_edge_cases() {
+        let empty = OwnedHeaders::new();
+        assert_eq!(empty.count(), 0);
+        assert!(empty.try_get(0).is_none());
+        assert_eq!(empty.iter().count(), 0);
+
+        let owned = OwnedHeaders::new()
+            .insert(Header {
+                key: "",
+                value: Some("empty key"),
+            })
+            .insert(Header {
+                key: "binary",
+                value: Some(&[0xff, 0x00, 0xfe][..]),
+            })
+            .insert(Header::<&[u8]> {
+                key: "binary",
+                value: None,
+            });
+        assert_eq!(owned.count(), 3);
+        assert_eq!(
+            owned.get_as::<str>(0),
+            Ok(Header {
+                key: "",
+                value: Some("empty key")
+            })
+        );
+        assert_eq!(
+            owned.get(1),
+            Header {
+                key: "binary",
+                value: Some(&[0xff, 0x00, 0xfe][..])
+            }
+        );
+        assert!(owned.get_as::<str>(1).is_err());
+        // Repeated keys are kept as separate headers, in insertion order.
+        assert_eq!(
+            owned.iter().map(|h| h.key).collect::<Vec<_>>(),
+            vec!["", "binary", "binary"]
+        );
+        assert_eq!(owned.get(2).value, None);
+        assert!(owned.try_get(3).is_none());
+    }
+
+    #[test]
+    fn test_json() {
+        let json = Json::new(&vec![("a", 1)]).unwrap();
+        assert_eq!(json.to_bytes(), b"[[\"a\",1]]");
+    }
+
+    #[test]
+    fn test_headers_from_iter() {
+        let pairs = [
+            ("trace-id".to_string(), b"abc".to_vec()),
+            ("schema-id".to_string(), vec![0, 0, 0, 42]),
+        ];
+        let owned = pairs
+            .iter()
+            .map(|(key, value)| Header {
+                key: key.as_str(),
+                value: Some(value.as_slice()),
+            })
+            .collect::<OwnedHeaders>();
+        assert_eq!(owned.count(), 2);
+        assert_eq!(
+            owned.iter().collect::<Vec<_>>(),
+            vec![
+                Header {
+                    key: "trace-id",
+                    value: Some(&b"abc"[..])
+                },
+                Header {
+                    key: "schema-id",
+                    value: Some(&[0, 0, 0, 42][..])
+                },
+            ]
+        );
+    }
 }
@@ -40,18 +40,28 @@
 //! polling thread is not fast enough or Kafka is not able to receive data and
 //! acknowledge messages quickly enough. If this error is returned, the caller
 //! should wait and try again.
+//!
+//! The polling thread waits for up to 100ms for new events on every
+//! iteration. The interval can be changed with
+//! [`ThreadedProducer::set_poll_interval`].
+//!
+//! Dropping a `ThreadedProducer` stops the polling thread without waiting for
+//! messages that are still in flight. Call [`Producer::flush`] before dropping
+//! it, or use [`ThreadedProducer::set_flush_on_drop`] to have the producer flush
+//! itself when it is dropped.
 
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::marker::PhantomData;
 use std::mem;
-use std::os::raw::c_void;
+use std::os::raw::{c_char, c_int, c_void};
 use std::ptr;
 use std::slice;
 use std::str;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use rdkafka_sys as rdsys;
 use rdkafka_sys::rd_kafka_vtype_t::*;
@@ -231,6 +241,20 @@ unsafe extern "C" fn partitioner_cb<Part: Partitioner, C: ProducerContext<Part>>
         .partition(topic_name, key, partition_cnt, is_partition_available)
 }
 
+unsafe extern "C" fn throttle_cb<Part: Partitioner, C: ProducerContext<Part>>(
+    _client: *mut RDKafka,
+    broker_name: *const c_char,
+    broker_id: i32,
+    throttle_time_ms: c_int,
+    opaque: *mut c_void,
+) {
+    let broker_name = CStr::from_ptr(broker_name).to_string_lossy();
+    let throttle_time = Duration::from_millis(throttle_time_ms.max(0) as u64);
+
+    let producer_context = &*(opaque as *const C);
+    producer_context.throttle(throttle_time, &broker_name, broker_id);
+}
+
 impl FromClientConfig for BaseProducer<DefaultProducerContext> {
     /// Creates a new `BaseProducer` starting from a configuration.
     fn from_config(config: &ClientConfig) -> KafkaResult<BaseProducer<DefaultProducerContext>> {
@@ -272,6 +296,11 @@ where
             }
         }
 
+        // Throttling is not reported through the event API, but the callback
+        // is served by the same queue poll that serves the events.
+        unsafe {
+            rdsys::rd_kafka_conf_set_throttle_cb(native_config.ptr(), Some(throttle_cb::<Part, C>))
+        };
         unsafe {
             rdsys::rd_kafka_conf_set_events(
                 native_config.ptr(),
@@ -378,6 +407,20 @@ where
         }
     }
 
+    /// Interrupts a call to [`BaseProducer::poll`] that is blocked in another
+    /// thread, making it return before its timeout expires.
+    ///
+    /// If no call to `poll` is in progress, the next one returns immediately.
+    pub fn interrupt_poll(&self) {
+        unsafe { rdsys::rd_kafka_queue_yield(self.queue.ptr()) }
+    }
+
+    /// Returns the number of events, such as delivery reports, that are
+    /// waiting to be served by [`BaseProducer::poll`].
+    pub fn event_queue_length(&self) -> usize {
+        unsafe { rdsys::rd_kafka_queue_length(self.queue.ptr()) }
+    }
+
     fn handle_delivery_report_event(&self, event: NativePtr<RDKafkaEvent>) {
         let max_messages = unsafe { rdsys::rd_kafka_event_message_count(event.ptr()) };
         let messages: Vec<*const RDKafkaMessage> = Vec::with_capacity(max_messages);
@@ -611,6 +654,19 @@ where
     }
 }
 
+impl<C, Part> fmt::Debug for BaseProducer<C, Part>
+where
+    Part: Partitioner,
+    C: ProducerContext<Part>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BaseProducer")
+            .field("name", &self.client.name())
+            .field("in_flight_count", &self.in_flight_count())
+            .finish()
+    }
+}
+
 impl<C, Part: Partitioner> Drop for BaseProducer<C, Part>
 where
     C: ProducerContext<Part>,
@@ -644,6 +700,8 @@ where
 {
     producer: Arc<BaseProducer<C, Part>>,
     should_stop: Arc<AtomicBool>,
+    poll_interval_ms: Arc<AtomicU64>,
+    flush_on_drop: Mutex<Option<Timeout>>,
     handle: Option<JoinHandle<()>>,
 }
 
@@ -664,15 +722,18 @@ where
     ) -> KafkaResult<ThreadedProducer<C, Part>> {
         let producer = Arc::new(BaseProducer::from_config_and_context(config, context)?);
         let should_stop = Arc::new(AtomicBool::new(false));
+        let poll_interval_ms = Arc::new(AtomicU64::new(100));
         let thread = {
             let producer = Arc::clone(&producer);
             let should_stop = should_stop.clone();
+            let poll_interval_ms = poll_interval_ms.clone();
             thread::Builder::new()
                 .name("producer polling thread".to_string())
                 .spawn(move || {
                     trace!("Polling thread loop started");
                     loop {
-                        producer.poll(Duration::from_millis(100));
+                        let poll_interval = poll_interval_ms.load(Ordering::Relaxed);
+                        producer.poll(Duration::from_millis(poll_interval));
                         if should_stop.load(Ordering::Relaxed) {
                             // We received nothing and the thread should
                             // stop, so break the loop.
@@ -686,6 +747,8 @@ where
         Ok(ThreadedProducer {
             producer,
             should_stop,
+            poll_interval_ms,
+            flush_on_drop: Mutex::new(None),
             handle: Some(thread),
         })
     }
@@ -712,6 +775,51 @@ where
         self.producer.send(record)
     }
 
+    /// Sends a message to Kafka, waiting for space in the producer queue if
+    /// it is full.
+    ///
+    /// The `queue_timeout` parameter controls how long to retry for if the
+    /// librdkafka producer queue is full. Set it to `Timeout::Never` to retry
+    /// forever or `Timeout::After(0)` to never block. If the timeout is reached
+    /// and the queue is still full, an [`RDKafkaErrorCode::QueueFull`] error is
+    /// returned alongside the original record.
+    ///
+    /// While waiting, this method polls the producer, so delivery callbacks may
+    /// be executed on the calling thread. See [`ThreadedProducer::send`] for
+    /// the non-blocking version.
+    pub fn send_blocking<'a, K, P, T>(
+        &self,
+        mut record: BaseRecord<'a, K, P, C::DeliveryOpaque>,
+        queue_timeout: T,
+    ) -> Result<(), (KafkaError, BaseRecord<'a, K, P, C::DeliveryOpaque>)>
+    where
+        K: ToBytes + ?Sized,
+        P: ToBytes + ?Sized,
+        T: Into<Timeout>,
+    {
+        let start_time = Instant::now();
+        let queue_timeout = queue_timeout.into();
+        loop {
+            match self.producer.send(record) {
+                Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), rec)) => {
+                    let remaining = queue_timeout.saturating_sub(start_time.elapsed());
+                    if remaining.is_zero() {
+                        return Err((
+                            KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull),
+                            rec,
+                        ));
+                    }
+                    record = rec;
+                    // Polling returns as soon as a delivery report frees up
+                    // space in the queue, or when the wait interval expires.
+                    self.producer
+                        .poll(std::cmp::min(remaining, self.producer.min_poll_interval));
+                }
+                result => return result,
+            }
+        }
+    }
+
     /// Polls the internal producer.
     ///
     /// This is not normally required since the `ThreadedProducer` has a thread
@@ -719,6 +827,38 @@ where
     pub fn poll<T: Into<Timeout>>(&self, timeout: T) {
         self.producer.poll(timeout);
     }
+
+    /// Returns the number of events waiting to be served by the polling
+    /// thread.
+    ///
+    /// See [`BaseProducer::event_queue_length`].
+    pub fn event_queue_length(&self) -> usize {
+        self.producer.event_queue_length()
+    }
+
+    /// Sets how long the polling thread waits for new events on every
+    /// iteration. Defaults to 100ms.
+    ///
+    /// Events are processed as soon as they are available, regardless of the
+    /// interval, and dropping the producer interrupts the wait, so a longer
+    /// interval only means fewer wakeups while the producer is idle. The
+    /// interval is truncated to whole milliseconds, and intervals shorter than
+    /// 1ms are rounded up to 1ms so that the thread never spins.
+    pub fn set_poll_interval(&self, interval: Duration) {
+        let interval_ms = std::cmp::max(interval.as_millis(), 1) as u64;
+        self.poll_interval_ms.store(interval_ms, Ordering::Relaxed);
+    }
+
+    /// Sets whether the producer flushes when it is dropped. With
+    /// `Some(timeout)`, dropping the producer waits up to `timeout` for
+    /// in-flight messages to be delivered before the polling thread is
+    /// stopped; `None` turns flushing on drop off again.
+    ///
+    /// By default the producer is not flushed on drop. Messages that are not
+    /// delivered within the timeout are lost, and a warning is logged.
+    pub fn set_flush_on_drop(&self, timeout: Option<Timeout>) {
+        *self.flush_on_drop.lock().unwrap_or_else(|e| e.into_inner()) = timeout;
+    }
 }
 
 impl<C, Part> Producer<C, Part> for ThreadedProducer<C, Part>
@@ -769,6 +909,19 @@ where
     }
 }
 
+impl<C, Part> fmt::Debug for ThreadedProducer<C, Part>
+where
+    Part: Partitioner,
+    C: ProducerContext<Part> + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadedProducer")
+            .field("name", &self.producer.client.name())
+            .field("in_flight_count", &self.in_flight_count())
+            .finish()
+    }
+}
+
 impl<C, Part> Drop for ThreadedProducer<C, Part>
 where
     Part: Partitioner,
@@ -776,9 +929,20 @@ where
 {
     fn drop(&mut self) {
         trace!("Destroy ThreadedProducer");
+        let flush_on_drop = *self
+            .flush_on_drop
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(timeout) = flush_on_drop {
+            trace!("Flushing ThreadedProducer");
+            if let Err(e) = self.producer.flush(timeout) {
+                warn!("Failed to flush producer on drop: {}", e);
+            }
+        }
         if let Some(handle) = self.handle.take() {
             trace!("Stopping polling");
             self.should_stop.store(true, Ordering::Relaxed);
+            self.producer.interrupt_poll();
             trace!("Waiting for polling thread termination");
             match handle.join() {
                 Ok(()) => trace!("Polling stopped"),
@@ -186,7 +186,10 @@ where
             Ok(ref message) => Ok((message.partition(), message.offset())),
             Err((ref error, ref message)) => Err((error.clone(), message.detach())),
         };
-        let _ = tx.send(owned_delivery_result); // TODO: handle error
+        // The receiver is gone if the `DeliveryFuture` was dropped before the
+        // delivery report arrived. Nobody is waiting for the result in that
+        // case, and the sender is freed together with its box right here.
+        let _ = tx.send(owned_delivery_result);
     }
 }
 
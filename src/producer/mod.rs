@@ -160,6 +160,7 @@
 //! [Transactional Producer]: https://github.com/edenhill/librdkafka/blob/master/INTRODUCTION.md#transactional-producer
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::client::{Client, ClientContext};
 use crate::consumer::ConsumerGroupMetadata;
@@ -209,6 +210,16 @@ pub trait ProducerContext<Part: Partitioner = NoCustomPartitioner>: ClientContex
     fn get_custom_partitioner(&self) -> Option<&Part> {
         None
     }
+
+    /// This method is called when a broker reports that it throttled the
+    /// producer, with the time the request was throttled for. A
+    /// `throttle_time` of zero means that the broker stopped throttling.
+    ///
+    /// It is called from the thread that polls the producer. Note that the
+    /// context of a [`FutureProducer`] is not a `ProducerContext`, so this
+    /// callback is only available on the low-level producers.
+    #[allow(unused_variables)]
+    fn throttle(&self, throttle_time: Duration, broker_name: &str, broker_id: i32) {}
 }
 
 /// Unassigned partition.
@@ -283,6 +294,9 @@ where
 
     /// Returns the number of messages that are either waiting to be sent or are
     /// sent but are waiting to be acknowledged.
+    ///
+    /// The count includes messages waiting to be retried, as well as delivery
+    /// reports and other events that have not been served by `poll` yet.
     fn in_flight_count(&self) -> i32;
 
     /// Flushes any pending messages.
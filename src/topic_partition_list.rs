@@ -5,6 +5,7 @@
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::fmt;
+use std::iter::FromIterator;
 use std::slice;
 use std::str;
 
@@ -154,6 +155,18 @@ impl<'a> TopicPartitionListElem<'a> {
         self.ptr.metadata = buf;
         self.ptr.metadata_size = metadata.len();
     }
+
+    /// Clones the content of the element into an
+    /// [`OwnedTopicPartitionListElem`], which can outlive the list.
+    pub fn detach(&self) -> OwnedTopicPartitionListElem {
+        OwnedTopicPartitionListElem {
+            topic: self.topic().to_owned(),
+            partition: self.partition(),
+            offset: self.offset(),
+            metadata: self.metadata().to_owned(),
+            error: self.error(),
+        }
+    }
 }
 
 impl<'a> PartialEq for TopicPartitionListElem<'a> {
@@ -165,6 +178,46 @@ impl<'a> PartialEq for TopicPartitionListElem<'a> {
     }
 }
 
+/// An element of a topic partition list that owns its data.
+///
+/// Created by [`TopicPartitionListElem::detach`] or by consuming a
+/// [`TopicPartitionList`] with [`IntoIterator::into_iter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedTopicPartitionListElem {
+    topic: String,
+    partition: i32,
+    offset: Offset,
+    metadata: String,
+    error: KafkaResult<()>,
+}
+
+impl OwnedTopicPartitionListElem {
+    /// Returns the topic name.
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Returns the optional error associated to the specific entry in the TPL.
+    pub fn error(&self) -> KafkaResult<()> {
+        self.error.clone()
+    }
+
+    /// Returns the partition number.
+    pub fn partition(&self) -> i32 {
+        self.partition
+    }
+
+    /// Returns the offset.
+    pub fn offset(&self) -> Offset {
+        self.offset
+    }
+
+    /// Returns the optional metadata associated with the entry.
+    pub fn metadata(&self) -> &str {
+        &self.metadata
+    }
+}
+
 /// A structure to store and manipulate a list of topics and partitions with optional offsets.
 pub struct TopicPartitionList {
     ptr: NativePtr<RDKafkaTopicPartitionList>,
@@ -335,6 +388,14 @@ impl TopicPartitionList {
         vec
     }
 
+    /// Returns an iterator over the elements of the list.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            list: self,
+            index: 0,
+        }
+    }
+
     /// Returns all the elements of the list that belong to the specified topic.
     pub fn elements_for_topic<'a>(&'a self, topic: &str) -> Vec<TopicPartitionListElem<'a>> {
         let slice = unsafe { slice::from_raw_parts_mut((*self.ptr).elems, self.count()) };
@@ -357,6 +418,91 @@ impl TopicPartitionList {
     }
 }
 
+/// An iterator over the elements of a [`TopicPartitionList`].
+///
+/// Created by [`TopicPartitionList::iter`].
+pub struct Iter<'a> {
+    list: &'a TopicPartitionList,
+    index: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = TopicPartitionListElem<'a>;
+
+    fn next(&mut self) -> Option<TopicPartitionListElem<'a>> {
+        if self.index >= self.list.count() {
+            return None;
+        }
+        let elem_ptr = unsafe { &mut *self.list.ptr.elems.add(self.index) };
+        self.index += 1;
+        Some(TopicPartitionListElem::from_ptr(self.list, elem_ptr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.count() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> IntoIterator for &'a TopicPartitionList {
+    type Item = TopicPartitionListElem<'a>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for TopicPartitionList {
+    type Item = OwnedTopicPartitionListElem;
+    type IntoIter = std::vec::IntoIter<OwnedTopicPartitionListElem>;
+
+    /// Consumes the list, returning its elements as
+    /// [`OwnedTopicPartitionListElem`]s.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+            .map(|elem| elem.detach())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<T> FromIterator<(T, i32)> for TopicPartitionList
+where
+    T: AsRef<str>,
+{
+    /// Builds a list from `(topic, partition)` pairs, without offsets.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (T, i32)>,
+    {
+        let iter = iter.into_iter();
+        let mut tpl = TopicPartitionList::with_capacity(iter.size_hint().0.max(1));
+        for (topic, partition) in iter {
+            tpl.add_partition(topic.as_ref(), partition);
+        }
+        tpl
+    }
+}
+
+impl<'a, T> FromIterator<&'a (T, i32)> for TopicPartitionList
+where
+    T: AsRef<str> + 'a,
+{
+    /// Builds a list from borrowed `(topic, partition)` pairs, without
+    /// offsets.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a (T, i32)>,
+    {
+        iter.into_iter()
+            .map(|(topic, partition)| (topic, *partition))
+            .collect()
+    }
+}
+
 impl PartialEq for TopicPartitionList {
     fn eq(&self, other: &TopicPartitionList) -> bool {
         if self.count() != other.count() {
@@ -399,6 +545,24 @@ impl fmt::Debug for TopicPartitionList {
     }
 }
 
+/// Formats the list compactly as `topic[partition]@offset` entries, e.g.
+/// `topic[0]@42, topic[1]@Invalid`.
+impl fmt::Display for TopicPartitionList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, elem) in self.elements().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}[{}]@", elem.topic(), elem.partition())?;
+            match elem.offset() {
+                Offset::Offset(offset) => write!(f, "{}", offset)?,
+                offset => write!(f, "{:?}", offset)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 unsafe impl Send for TopicPartitionList {}
 unsafe impl Sync for TopicPartitionList {}
 
@@ -465,6 +629,63 @@ mod tests {
         assert_eq!(tp3.offset(), Offset::Offset(1234));
     }
 
+    #[test]
+    fn display() {
+        let mut tpl = TopicPartitionList::new();
+        assert_eq!(tpl.to_string(), "");
+
+        tpl.add_partition_offset("topic", 0, Offset::Offset(42))
+            .unwrap();
+        tpl.add_partition("topic", 1);
+        tpl.add_partition_offset("other", 2, Offset::Beginning)
+            .unwrap();
+        tpl.add_partition_offset("other", 3, Offset::OffsetTail(5))
+            .unwrap();
+        assert_eq!(
+            tpl.to_string(),
+            "topic[0]@42, topic[1]@Invalid, other[2]@Beginning, other[3]@OffsetTail(5)"
+        );
+    }
+
+    #[test]
+    fn iterate_and_collect() {
+        let tpl: TopicPartitionList = [("topic1", 0), ("topic1", 1), ("topic2", 0)]
+            .iter()
+            .collect();
+        assert_eq!(tpl.count(), 3);
+        assert_eq!(tpl.iter().len(), 3);
+
+        let mut seen = Vec::new();
+        for elem in &tpl {
+            seen.push((elem.topic().to_owned(), elem.partition()));
+        }
+        assert_eq!(
+            seen,
+            vec![
+                ("topic1".to_owned(), 0),
+                ("topic1".to_owned(), 1),
+                ("topic2".to_owned(), 0),
+            ]
+        );
+
+        tpl.find_partition("topic1", 1)
+            .unwrap()
+            .set_offset(Offset::Offset(42))
+            .unwrap();
+        let owned: Vec<_> = tpl.into_iter().collect();
+        assert_eq!(owned.len(), 3);
+        assert_eq!(owned[1].topic(), "topic1");
+        assert_eq!(owned[1].partition(), 1);
+        assert_eq!(owned[1].offset(), Offset::Offset(42));
+        assert_eq!(owned[1].metadata(), "");
+        assert_eq!(owned[1].error(), Ok(()));
+
+        let empty: TopicPartitionList = Vec::<(String, i32)>::new().into_iter().collect();
+        assert_eq!(empty.count(), 0);
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(empty.into_iter().count(), 0);
+    }
+
     #[test]
     fn add_partition_range() {
         let mut tpl = TopicPartitionList::new();
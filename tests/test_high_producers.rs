@@ -195,3 +195,52 @@ async fn test_future_undelivered() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_future_producer_message_timeout() {
+    let mut config = HashMap::new();
+    // There's no server running there
+    config.insert("bootstrap.servers", "localhost:47021");
+    config.insert("message.timeout.ms", "100");
+    let producer = future_producer(config);
+
+    let result = producer
+        .send(
+            FutureRecord::to("topic").payload("payload").key("key"),
+            Duration::from_secs(0),
+        )
+        .await;
+
+    match result {
+        Err((KafkaError::MessageProduction(RDKafkaErrorCode::MessageTimedOut), owned_message)) => {
+            assert_eq!(owned_message.topic(), "topic");
+            assert_eq!(owned_message.payload(), Some(b"payload" as _));
+        }
+        v => panic!("Unexpected return value: {:?}", v),
+    }
+}
+
+#[tokio::test]
+async fn test_future_producer_drop_future() {
+    let mut config = HashMap::new();
+    // There's no server running there
+    config.insert("bootstrap.servers", "localhost:47021");
+    config.insert("message.timeout.ms", "100");
+    let producer = future_producer(config);
+
+    // Drop the delivery futures before the messages time out. The delivery
+    // callback must still run for every message, even though nobody is
+    // waiting for the result. That the callback releases the boxed sender it
+    // receives is covered by `test_base_producer_releases_boxed_opaques`.
+    for _ in 0..10 {
+        let delivery_future = producer
+            .send_result(FutureRecord::to("topic").payload("payload").key("key"))
+            .expect("Failed to queue message");
+        drop(delivery_future);
+    }
+
+    // Flushing waits for all delivery reports; it fails since the messages
+    // could not be delivered, but none of them should be left in flight.
+    let _ = producer.flush(Duration::from_secs(5));
+    assert_eq!(producer.in_flight_count(), 0);
+}
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
 
-use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
+use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer, ConsumerContext};
 use rdkafka::error::{KafkaError, RDKafkaErrorCode};
 use rdkafka::topic_partition_list::{Offset, TopicPartitionList};
 use rdkafka::util::{current_time_millis, Timeout};
@@ -26,6 +26,16 @@ fn create_base_consumer(
         .expect("Consumer creation failed")
 }
 
+// Creates a consumer pointed at a broker that does not exist, for tests that
+// only exercise client-side behavior.
+fn create_unconnected_consumer() -> BaseConsumer {
+    ClientConfig::new()
+        .set("group.id", rand_test_group())
+        .set("bootstrap.servers", "localhost:47021")
+        .create()
+        .unwrap()
+}
+
 // Seeking should allow replaying messages and skipping messages.
 #[tokio::test]
 async fn test_produce_consume_seek() {
@@ -180,6 +190,30 @@ async fn test_produce_consume_iter() {
     }
 }
 
+// After consuming, the cached high watermark should match the fetched one.
+#[tokio::test]
+async fn test_produce_consume_cached_watermarks() {
+    let _r = env_logger::try_init();
+
+    let topic_name = rand_test_topic("test_produce_consume_cached_watermarks");
+    populate_topic(&topic_name, 10, &value_fn, &key_fn, Some(0), None).await;
+    let consumer = create_base_consumer(&rand_test_group(), None);
+    consumer.subscribe(&[topic_name.as_str()]).unwrap();
+
+    for message in consumer.iter().take(10) {
+        if let Err(e) = message {
+            panic!("Error receiving message: {:?}", e);
+        }
+    }
+
+    let (_, cached_high) = consumer.cached_watermarks(&topic_name, 0).unwrap();
+    let (_, high) = consumer
+        .fetch_watermarks(&topic_name, 0, Duration::from_secs(5))
+        .unwrap();
+    assert_eq!(cached_high, high);
+    assert_eq!(high, 10);
+}
+
 fn ensure_empty<C: ConsumerContext>(consumer: &BaseConsumer<C>, err_msg: &str) {
     const MAX_TRY_TIME: Duration = Duration::from_secs(2);
     let start = Instant::now();
@@ -233,6 +267,29 @@ async fn test_pause_resume_consumer_iter() {
     ensure_empty(&consumer, "There should be no messages left");
 }
 
+// Batched polling should return up to the requested number of messages.
+#[tokio::test]
+async fn test_produce_consume_poll_batch() {
+    let _r = env_logger::try_init();
+
+    let topic_name = rand_test_topic("test_produce_consume_poll_batch");
+    populate_topic(&topic_name, 10, &value_fn, &key_fn, Some(0), None).await;
+    let consumer = create_base_consumer(&rand_test_group(), None);
+    consumer.subscribe(&[topic_name.as_str()]).unwrap();
+
+    let batch = consumer.poll_batch(7, Timeout::Never);
+    assert_eq!(batch.len(), 7);
+    for (i, message) in batch.into_iter().enumerate() {
+        assert_eq!(message.unwrap().offset(), i as i64);
+    }
+
+    let batch = consumer.poll_batch(7, Duration::from_secs(5));
+    assert_eq!(batch.len(), 3);
+    for (i, message) in batch.into_iter().enumerate() {
+        assert_eq!(message.unwrap().offset(), i as i64 + 7);
+    }
+}
+
 #[tokio::test]
 async fn test_consume_partition_order() {
     let _r = env_logger::try_init();
@@ -457,3 +514,145 @@ async fn test_invalid_consumer_position() {
         Err(KafkaError::MetadataFetch(RDKafkaErrorCode::UnknownGroup))
     );
 }
+
+// Committing a message through a consumer of another group commits the offset
+// for that consumer's group, and leaves the group that consumed it untouched.
+#[tokio::test]
+async fn test_commit_message_other_group() {
+    let _r = env_logger::try_init();
+
+    let topic_name = rand_test_topic("test_commit_message_other_group");
+    populate_topic(&topic_name, 5, &value_fn, &key_fn, Some(0), None).await;
+    let consumer = create_base_consumer(&rand_test_group(), None);
+    let other_consumer = create_base_consumer(&rand_test_group(), None);
+
+    let mut tpl = TopicPartitionList::new();
+    tpl.add_partition_offset(&topic_name, 0, Offset::Beginning)
+        .unwrap();
+    consumer.assign(&tpl).unwrap();
+    let message = consumer.poll(Timeout::Never).unwrap().unwrap();
+    assert_eq!(message.offset(), 0);
+
+    other_consumer
+        .commit_message(&message, CommitMode::Sync)
+        .unwrap();
+
+    let timeout = Duration::from_secs(5);
+    let mut partition = TopicPartitionList::new();
+    partition.add_partition(&topic_name, 0);
+    let committed = other_consumer
+        .committed_offsets(partition.clone(), timeout)
+        .unwrap();
+    assert_eq!(
+        committed.find_partition(&topic_name, 0).unwrap().offset(),
+        Offset::Offset(1)
+    );
+    let committed = consumer.committed_offsets(partition, timeout).unwrap();
+    assert_eq!(
+        committed.find_partition(&topic_name, 0).unwrap().offset(),
+        Offset::Invalid
+    );
+}
+
+#[test]
+fn test_commit_without_assignment() {
+    // Committing the consumer state when nothing is assigned must report that
+    // there was nothing to commit, rather than silently succeeding.
+    let consumer = create_unconnected_consumer();
+    assert_eq!(
+        consumer.commit_consumer_state(CommitMode::Sync),
+        Err(KafkaError::ConsumerCommit(RDKafkaErrorCode::NoOffset))
+    );
+}
+
+#[test]
+fn test_consumer_close() {
+    // Closing a consumer that never joined its group completes right away,
+    // and closing it again is a no-op.
+    let consumer = create_unconnected_consumer();
+    assert!(!consumer.closed());
+    consumer.close().unwrap();
+    assert!(consumer.closed());
+    consumer.close().unwrap();
+}
+
+#[test]
+fn test_assign_stored_offset() {
+    // Assigning a partition at `Offset::Stored` is accepted as is; the
+    // committed offset is resolved by librdkafka once the consumer fetches.
+    let consumer = create_unconnected_consumer();
+    let mut tpl = TopicPartitionList::new();
+    tpl.add_partition_offset("topic", 0, Offset::Stored)
+        .unwrap();
+    consumer.assign(&tpl).unwrap();
+    assert_eq!(consumer.assignment().unwrap().count(), 1);
+}
+
+#[test]
+fn test_poll_batch_without_messages() {
+    // Polling a batch returns once the timeout expires when there is nothing
+    // to consume, well before the consumer's internal poll interval of one
+    // second. The batch may still contain the error reporting that the broker
+    // is unreachable.
+    let consumer = create_unconnected_consumer();
+
+    let start = Instant::now();
+    let batch = consumer.poll_batch(10, Duration::ZERO);
+    assert!(start.elapsed() < Duration::from_millis(100));
+    assert!(batch.iter().all(|result| result.is_err()));
+
+    let start = Instant::now();
+    let batch = consumer.poll_batch(10, Duration::from_millis(200));
+    assert!(start.elapsed() < Duration::from_millis(600));
+    assert!(batch.iter().all(|result| result.is_err()));
+}
+
+#[test]
+fn test_seek_unassigned_partition() {
+    // Seeking requires the partition to be assigned to the consumer.
+    let consumer = create_unconnected_consumer();
+    match consumer.seek("topic", 0, Offset::Beginning, Duration::from_secs(1)) {
+        Err(KafkaError::Seek(s)) => assert_eq!(s, "Local: Unknown partition"),
+        bad => panic!("unexpected return from seek: {:?}", bad),
+    }
+}
+
+#[test]
+fn test_cached_watermarks_not_cached() {
+    // Without an assignment there is nothing cached for the partition.
+    let consumer = create_unconnected_consumer();
+    let invalid = Offset::Invalid.to_raw().unwrap();
+    assert_eq!(
+        consumer.cached_watermarks("topic", 0),
+        Ok((invalid, invalid))
+    );
+}
+
+#[test]
+fn test_store_offset_with_auto_offset_store() {
+    // Storing offsets explicitly is rejected while automatic offset storing
+    // is enabled, which is the default.
+    let consumer = create_unconnected_consumer();
+    let mut tpl = TopicPartitionList::new();
+    tpl.add_partition_offset("topic", 0, Offset::Offset(5))
+        .unwrap();
+    assert_eq!(
+        consumer.store_offsets(&tpl),
+        Err(KafkaError::StoreOffset(RDKafkaErrorCode::InvalidArgument))
+    );
+}
+
+#[test]
+fn test_pause_unassigned_partition() {
+    let consumer = create_unconnected_consumer();
+    let mut tpl = TopicPartitionList::new();
+    tpl.add_partition("topic", 0);
+
+    // The call itself succeeds, while the error for the partition is reported
+    // in the list.
+    consumer.pause(&tpl).unwrap();
+    assert_eq!(
+        tpl.elements()[0].error(),
+        Err(KafkaError::OffsetFetch(RDKafkaErrorCode::UnknownPartition))
+    );
+}
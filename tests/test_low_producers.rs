@@ -3,9 +3,11 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::ffi::CString;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::time::Duration;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use maplit::hashmap;
 
@@ -14,7 +16,7 @@ use rdkafka::error::{KafkaError, RDKafkaErrorCode};
 use rdkafka::message::{Header, Headers, Message, OwnedHeaders, OwnedMessage};
 use rdkafka::producer::{
     BaseProducer, BaseRecord, DeliveryResult, NoCustomPartitioner, Partitioner, Producer,
-    ProducerContext, ThreadedProducer,
+    ProducerContext, PurgeConfig, ThreadedProducer,
 };
 use rdkafka::types::RDKafkaRespErr;
 use rdkafka::util::current_time_millis;
@@ -166,7 +168,6 @@ fn base_producer_with_context<Part: Partitioner, C: ProducerContext<Part>>(
         .unwrap()
 }
 
-#[allow(dead_code)]
 fn threaded_producer(
     config_overrides: HashMap<&str, &str>,
 ) -> ThreadedProducer<PrintingContext, NoCustomPartitioner> {
@@ -268,6 +269,171 @@ fn test_base_producer_timeout() {
     assert_eq!(ids.len(), 10);
 }
 
+// Holds a boxed `Arc` for every message in flight, like the boxed senders of
+// `FutureProducer`.
+struct BoxedOpaqueContext;
+
+impl ClientContext for BoxedOpaqueContext {}
+
+impl ProducerContext for BoxedOpaqueContext {
+    type DeliveryOpaque = Box<Arc<()>>;
+
+    fn delivery(&self, _: &DeliveryResult, _: Self::DeliveryOpaque) {}
+}
+
+// The delivery callback takes ownership of the boxed opaque, so every box must
+// be released once its message has been reported, even when delivery failed.
+#[test]
+fn test_base_producer_releases_boxed_opaques() {
+    let producer = base_producer_with_context(
+        BoxedOpaqueContext,
+        hashmap! {
+            // There's no server running there
+            "bootstrap.servers" => "localhost:47021",
+            "message.timeout.ms" => "100",
+        },
+    );
+    let tracker = Arc::new(());
+
+    for _ in 0..10 {
+        producer
+            .send(
+                BaseRecord::with_opaque_to("topic", Box::new(Arc::clone(&tracker)))
+                    .payload("A")
+                    .key("B"),
+            )
+            .unwrap();
+    }
+    assert_eq!(Arc::strong_count(&tracker), 11);
+
+    let _ = producer.flush(Duration::from_secs(5));
+    assert_eq!(producer.in_flight_count(), 0);
+    assert_eq!(Arc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn test_base_producer_purge() {
+    let context = CollectingContext::new();
+    let producer = base_producer_with_context(
+        context.clone(),
+        hashmap! {
+            "message.timeout.ms" => "60000",
+            "bootstrap.servers" => "1.2.3.4"
+        },
+    );
+    let topic_name = rand_test_topic("test_base_producer_purge");
+
+    for id in 0..10 {
+        producer
+            .send(
+                BaseRecord::with_opaque_to(&topic_name, id)
+                    .payload("A")
+                    .key("B"),
+            )
+            .unwrap();
+    }
+
+    // The messages can't leave the queue, so purging must fail all of them
+    // without waiting for the message timeout.
+    producer.purge(PurgeConfig::default().queue().inflight());
+    producer.flush(Duration::from_secs(10)).unwrap();
+
+    let delivery_results = context.results.lock().unwrap();
+    assert_eq!(delivery_results.len(), 10);
+    for (_, error, _) in &(*delivery_results) {
+        assert_eq!(
+            error,
+            &Some(KafkaError::MessageProduction(RDKafkaErrorCode::PurgeQueue))
+        );
+    }
+}
+
+#[test]
+fn test_producer_debug() {
+    let config = hashmap! { "client.id" => "debug" };
+    let producer = base_producer(config.clone());
+    assert!(format!("{:?}", producer).starts_with("BaseProducer { name: \"debug#producer-"));
+    assert!(format!("{:?}", producer).ends_with("in_flight_count: 0 }"));
+
+    let producer = threaded_producer(config);
+    assert!(format!("{:?}", producer).starts_with("ThreadedProducer { name: \"debug#producer-"));
+}
+
+#[test]
+fn test_base_producer_event_queue_length() {
+    let producer = base_producer(hashmap! { "test.mock.num.brokers" => "1" });
+    let topic_name = rand_test_topic("test_base_producer_event_queue_length");
+
+    assert_eq!(producer.event_queue_length(), 0);
+    producer
+        .send(
+            BaseRecord::with_opaque_to(&topic_name, 0)
+                .payload("A")
+                .key("B"),
+        )
+        .unwrap();
+
+    // Wait for the delivery report to be enqueued. Until it is served, it
+    // still counts as in flight.
+    let start = Instant::now();
+    while producer.event_queue_length() == 0 {
+        assert!(start.elapsed() < Duration::from_secs(10));
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(producer.event_queue_length(), 1);
+    assert!(producer.in_flight_count() >= 1);
+
+    producer.poll(Duration::ZERO);
+    assert_eq!(producer.event_queue_length(), 0);
+}
+
+#[test]
+fn test_base_producer_flush_timeout() {
+    let producer = base_producer(hashmap! {
+        "message.timeout.ms" => "5000",
+        "bootstrap.servers" => "1.2.3.4"
+    });
+    let topic_name = rand_test_topic("test_base_producer_flush_timeout");
+
+    producer
+        .send(
+            BaseRecord::with_opaque_to(&topic_name, 0)
+                .payload("A")
+                .key("B"),
+        )
+        .unwrap();
+
+    // The message can't be delivered, so a flush with no time to wait must
+    // report the outstanding message instead of returning successfully.
+    assert_eq!(
+        producer.flush(Duration::ZERO),
+        Err(KafkaError::Flush(RDKafkaErrorCode::OperationTimedOut))
+    );
+    assert_eq!(producer.in_flight_count(), 1);
+}
+
+#[test]
+fn test_threaded_producer_flush_timeout() {
+    let producer = threaded_producer(hashmap! {
+        "message.timeout.ms" => "5000",
+        "bootstrap.servers" => "1.2.3.4"
+    });
+    let topic_name = rand_test_topic("test_threaded_producer_flush_timeout");
+
+    producer
+        .send(
+            BaseRecord::with_opaque_to(&topic_name, 0)
+                .payload("A")
+                .key("B"),
+        )
+        .unwrap();
+
+    assert_eq!(
+        producer.flush(Duration::ZERO),
+        Err(KafkaError::Flush(RDKafkaErrorCode::OperationTimedOut))
+    );
+}
+
 struct HeaderCheckContext {
     ids: Arc<Mutex<HashSet<usize>>>,
 }
@@ -413,6 +579,268 @@ fn test_threaded_producer_send() {
     }
 }
 
+#[test]
+fn test_base_producer_interrupt_poll() {
+    // Use a mock cluster, so that no connection errors wake up the poller.
+    let producer = Arc::new(base_producer(hashmap! { "test.mock.num.brokers" => "1" }));
+
+    let poller = {
+        let producer = Arc::clone(&producer);
+        thread::spawn(move || {
+            let start = Instant::now();
+            producer.poll(Duration::from_secs(30));
+            start.elapsed()
+        })
+    };
+    thread::sleep(Duration::from_millis(100));
+    assert!(!poller.is_finished());
+    producer.interrupt_poll();
+
+    assert!(poller.join().unwrap() < Duration::from_secs(10));
+}
+
+#[test]
+fn test_threaded_producer_drop_interrupts_poll() {
+    // Use a mock cluster, so that no connection errors wake up the polling
+    // thread.
+    let producer = threaded_producer(hashmap! { "test.mock.num.brokers" => "1" });
+    producer.set_poll_interval(Duration::from_secs(30));
+    // Let the polling thread pick up the new interval.
+    thread::sleep(Duration::from_millis(200));
+
+    let start = Instant::now();
+    drop(producer);
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+// Returns the ids of the threads in this process that are named like the
+// `ThreadedProducer` polling thread. Thread names are truncated to 15 bytes.
+#[cfg(target_os = "linux")]
+fn polling_thread_ids() -> HashSet<String> {
+    std::fs::read_dir("/proc/self/task")
+        .unwrap()
+        .filter_map(|entry| {
+            let tid = entry.ok()?.file_name().into_string().ok()?;
+            let comm = std::fs::read_to_string(format!("/proc/self/task/{}/comm", tid)).ok()?;
+            (comm.trim_end() == "producer pollin").then(|| tid)
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn voluntary_context_switches(tid: &str) -> u64 {
+    let status = std::fs::read_to_string(format!("/proc/self/task/{}/status", tid)).unwrap();
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("voluntary_ctxt_switches:"))
+        .unwrap()
+        .trim()
+        .parse()
+        .unwrap()
+}
+
+// The poll interval determines how often an idle polling thread wakes up.
+// Every wakeup is a voluntary context switch, so a 1ms interval must cause far
+// more of them than the 100ms default would. Intervals below 1ms are rounded
+// up, so the thread sleeps between polls instead of spinning, which would not
+// cause any voluntary context switches.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_threaded_producer_poll_interval() {
+    let existing_threads = polling_thread_ids();
+    let producer = threaded_producer(hashmap! { "bootstrap.servers" => "" });
+    // The thread names itself once it is running. Other tests may start
+    // polling threads concurrently; they can only add to the count.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let new_threads: Vec<_> = loop {
+        let new_threads: Vec<_> = polling_thread_ids()
+            .difference(&existing_threads)
+            .cloned()
+            .collect();
+        if !new_threads.is_empty() || Instant::now() > deadline {
+            break new_threads;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    assert!(!new_threads.is_empty());
+    let switches = || -> u64 {
+        new_threads
+            .iter()
+            .map(|tid| voluntary_context_switches(tid))
+            .sum()
+    };
+
+    producer.set_poll_interval(Duration::from_micros(500));
+    // Let the polling thread pick up the new interval.
+    thread::sleep(Duration::from_millis(200));
+    let before = switches();
+    thread::sleep(Duration::from_millis(500));
+    let after = switches();
+
+    // About 500 wakeups are expected. The default interval would give about 5.
+    assert!(after - before >= 100, "only {} wakeups", after - before);
+}
+
+#[test]
+fn test_threaded_producer_send_blocking() {
+    // Connect to a nonexistent Kafka broker with a tiny producer queue, so we
+    // can fill up the queue by sending a single message.
+    let producer = threaded_producer(hashmap! {
+        "bootstrap.servers" => "",
+        "message.timeout.ms" => "5000",
+        "queue.buffering.max.messages" => "1",
+    });
+    let topic_name = rand_test_topic("test_threaded_producer_send_blocking");
+
+    producer
+        .send(
+            BaseRecord::with_opaque_to(&topic_name, 0)
+                .payload("A")
+                .key("B"),
+        )
+        .unwrap();
+
+    let send_message = |timeout| {
+        let start = Instant::now();
+        match producer.send_blocking(
+            BaseRecord::with_opaque_to(&topic_name, 1)
+                .payload("A")
+                .key("B"),
+            timeout,
+        ) {
+            Ok(_) => panic!("send unexpectedly succeeded"),
+            Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), _)) => start.elapsed(),
+            Err((e, _)) => panic!("got incorrect error: {}", e),
+        }
+    };
+
+    // Without a timeout, the error should be returned approximately
+    // immediately.
+    let elapsed = send_message(Duration::from_secs(0));
+    assert!(elapsed < Duration::from_millis(20));
+
+    // With a 1s timeout, the error should be returned in about 1s.
+    let elapsed = send_message(Duration::from_secs(1));
+    assert!(elapsed > Duration::from_millis(800));
+    assert!(elapsed < Duration::from_millis(1200));
+}
+
+#[test]
+fn test_threaded_producer_send_blocking_queue_drained() {
+    let producer = threaded_producer(hashmap! {
+        "bootstrap.servers" => "",
+        "message.timeout.ms" => "100",
+        "queue.buffering.max.messages" => "1",
+    });
+    let topic_name = rand_test_topic("test_threaded_producer_send_blocking_queue_drained");
+
+    producer
+        .send(
+            BaseRecord::with_opaque_to(&topic_name, 0)
+                .payload("A")
+                .key("B"),
+        )
+        .unwrap();
+
+    // The first message times out and leaves the queue, making space for the
+    // second one before the queue timeout expires.
+    producer
+        .send_blocking(
+            BaseRecord::with_opaque_to(&topic_name, 1)
+                .payload("A")
+                .key("B"),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_base_producer_delivery_latency() {
+    // Whether delivery succeeded, the latency and the broker id.
+    type LatencyResult = (bool, Option<Duration>, Option<i32>);
+
+    struct LatencyContext {
+        results: Mutex<Vec<LatencyResult>>,
+    }
+
+    impl ClientContext for LatencyContext {}
+
+    impl ProducerContext for LatencyContext {
+        type DeliveryOpaque = ();
+
+        fn delivery(&self, delivery_result: &DeliveryResult, _: Self::DeliveryOpaque) {
+            let (ok, message) = match delivery_result {
+                Ok(message) => (true, message),
+                Err((_, message)) => (false, message),
+            };
+            self.results
+                .lock()
+                .unwrap()
+                .push((ok, message.latency(), message.broker_id()));
+        }
+    }
+
+    // Produce to a mock cluster, so that delivery succeeds without a broker.
+    let producer = base_producer_with_context(
+        LatencyContext {
+            results: Mutex::new(Vec::new()),
+        },
+        hashmap! { "test.mock.num.brokers" => "1" },
+    );
+    let topic_name = rand_test_topic("test_base_producer_delivery_latency");
+
+    producer
+        .send(BaseRecord::to(&topic_name).payload("A").key("B"))
+        .unwrap();
+    producer.flush(Duration::from_secs(10)).unwrap();
+
+    let results = producer.context().results.lock().unwrap();
+    assert_eq!(results.len(), 1);
+    let (ok, latency, broker_id) = results[0];
+    assert!(ok);
+    assert!(latency.is_some());
+    assert_eq!(broker_id, Some(1));
+}
+
+#[test]
+fn test_threaded_producer_flush_on_drop() {
+    struct CountingContext {
+        delivered: Arc<AtomicUsize>,
+    }
+
+    impl ClientContext for CountingContext {}
+
+    impl ProducerContext for CountingContext {
+        type DeliveryOpaque = ();
+
+        fn delivery(&self, delivery_result: &DeliveryResult, _: Self::DeliveryOpaque) {
+            if delivery_result.is_ok() {
+                self.delivered.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    let delivered = Arc::new(AtomicUsize::new(0));
+    let producer = threaded_producer_with_context(
+        CountingContext {
+            delivered: delivered.clone(),
+        },
+        hashmap! {
+            "test.mock.num.brokers" => "1",
+            "queue.buffering.max.ms" => "1000",
+        },
+    );
+    producer.set_flush_on_drop(Some(Duration::from_secs(10).into()));
+    let topic_name = rand_test_topic("test_threaded_producer_flush_on_drop");
+
+    producer
+        .send(BaseRecord::to(&topic_name).payload("A").key("B"))
+        .unwrap();
+    drop(producer);
+
+    assert_eq!(delivered.load(Ordering::SeqCst), 1);
+}
+
 #[test]
 fn test_base_producer_opaque_arc() -> Result<(), Box<dyn Error>> {
     struct OpaqueArcContext {}